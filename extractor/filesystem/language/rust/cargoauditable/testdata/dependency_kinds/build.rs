@@ -0,0 +1,7 @@
+// Build script for the dependency_kinds fixture. Using `cc` here makes it a
+// genuine build-dependency that cargo-auditable records in `.dep-v0` with
+// kind = "build", so the extractor can classify and suppress it.
+
+fn main() {
+    cc::Build::new().file("noop.c").compile("noop");
+}
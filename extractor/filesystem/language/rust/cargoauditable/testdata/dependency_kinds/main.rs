@@ -0,0 +1,37 @@
+// Source Code used to create the dependency_kinds binary.
+//
+// Built with `cargo auditable build --release` so that the resolved
+// dependency tree (names, exact versions, and runtime/build kind) is embedded
+// in the `.dep-v0` ELF section.
+//
+// `cargo auditable build --release` compiles the normal binary *without* the
+// test harness, so crates used only behind `#[cfg(test)]` are never part of
+// the resolved graph and do not appear in `.dep-v0`. A pure dev-dependency
+// therefore cannot be demonstrated from a binary at all. What the fixture does
+// exercise is dependency-kind classification that *is* embedded:
+//
+//   - `serde_json` is a normal runtime dependency (no group);
+//   - `cc` is a build-dependency (kind = "build"), which production scans
+//     suppress via its "build" dependency group.
+
+/* Cargo.toml
+
+[package]
+name = "dependency_kinds"
+version = "0.1.0"
+edition = "2021"
+
+[dependencies]
+serde_json = "1.0"
+
+[build-dependencies]
+cc = "1.0"
+*/
+
+use serde_json::Value;
+
+fn main() {
+    let data = r#"{"name": "foo", "id": 314}"#;
+    let v: Value = serde_json::from_str(data).unwrap();
+    println!("name = {}", v["name"]);
+}
@@ -0,0 +1,51 @@
+// Source Code used to create the no_std_thumbv7 binary.
+//
+// Cross-compiled for a bare-metal target with
+//   cargo build --release --target thumbv7em-none-eabihf
+// (a riscv32imac-unknown-none-elf build is produced the same way). The
+// resulting ELF has no std panic/backtrace strings and a non-x86 machine
+// type, so the extractor must detect the `no_std` target from the ELF header,
+// skip the std-specific heuristics, and fall back to the cargo-auditable
+// section plus registry-path fingerprinting. The detected target triple is
+// recorded on the emitted package inventory for target-specific advisories.
+
+/* Cargo.toml
+
+[package]
+name = "no_std_thumbv7"
+version = "0.1.0"
+edition = "2021"
+
+[dependencies]
+serde = { version = "1.0", default-features = false, features = ["derive"] }
+serde-json-core = "0.5"
+
+[profile.release]
+panic = "abort"
+*/
+
+#![no_std]
+#![no_main]
+
+use core::panic::PanicInfo;
+
+use serde::Deserialize;
+
+#[derive(Deserialize)]
+struct Thingy<'a> {
+    name: &'a str,
+    id: u32,
+}
+
+#[no_mangle]
+pub extern "C" fn _start() -> ! {
+    let data = br#"{"name": "foo", "id": 314}"#;
+    let (obj, _): (Thingy, usize) = serde_json_core::from_slice(data).unwrap();
+    let _ = (obj.name, obj.id);
+    loop {}
+}
+
+#[panic_handler]
+fn panic(_info: &PanicInfo) -> ! {
+    loop {}
+}
@@ -0,0 +1,40 @@
+// Source Code used to create the stripped_smallvec binary.
+//
+// Built with `strip = true` and WITHOUT cargo-auditable, so there is no
+// manifest and no symbol table at runtime. Two version signals survive in the
+// read-only data, and the fingerprinting pass must recover both:
+//
+//   1. the registry source path leaked into panic/backtrace metadata, e.g.
+//        .../registry/src/index.crates.io-.../smallvec-1.6.0/src/lib.rs
+//      which pins the *dependency* version; and
+//   2. the literal `CARGO_PKG_VERSION` string embedded by the line below,
+//      exercising the bare-semver literal path.
+//
+// smallvec is pinned to the vulnerable `=1.6.0` so the fingerprinting pass must
+// resolve it to `<1.6.1` (RUSTSEC-2021-0003) rather than the fixed `1.6.1`.
+
+/* Cargo.toml
+
+[package]
+name = "stripped_smallvec"
+version = "0.1.0"
+edition = "2021"
+
+[dependencies]
+smallvec = "=1.6.0"
+
+[profile.release]
+strip = true
+*/
+
+use smallvec::SmallVec;
+
+fn main() {
+    let mut v: SmallVec<[i32; 4]> = SmallVec::new();
+    v.push(1);
+    v.insert_many(1, [2, 3]);
+
+    // Embeds a bare CARGO_PKG_VERSION literal into rodata for the
+    // fingerprinting pass to pick up.
+    println!("stripped_smallvec {} {:?}", env!("CARGO_PKG_VERSION"), v);
+}